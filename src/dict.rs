@@ -1,15 +1,74 @@
+use std::cell::RefCell;
+use std::collections::HashSet;
 use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
 use dashmap::DashMap;
-use pyo3::exceptions::PyKeyError;
+use pyo3::exceptions::{PyKeyError, PyRuntimeError};
 use pyo3::prelude::*;
-use pyo3::types::{PyAny, PyAnyMethods};
+use pyo3::types::{PyAny, PyAnyMethods, PyDict, PyDictMethods, PyTuple};
+use rayon::prelude::*;
 
 use pyo3::ffi;
 
+/// Below this many entries, the per-chunk `Python::attach` overhead of the `par_*` bulk
+/// operations costs more than it saves; fall back to a single-threaded loop that never
+/// leaves the caller's own GIL hold.
+const PAR_SEQUENTIAL_THRESHOLD: usize = 1024;
+
 type PyObject = Py<PyAny>;
 
+thread_local! {
+    // Identifies, by `Arc` pointer address, which `ConcurrentDict`s this thread currently
+    // has a `compute`/`get_or_insert_with`/`par_*` callback running for. The DashMap
+    // holds the shard lock across that callback, so a callback that calls back into the
+    // same dict (even via a different key that happens to land on the same shard) would
+    // deadlock rather than panic; this lets us detect and reject it instead.
+    static ACTIVE_COMPUTES: RefCell<HashSet<usize>> = RefCell::new(HashSet::new());
+}
+
+struct ReentrancyGuard {
+    identity: usize,
+}
+
+impl ReentrancyGuard {
+    fn enter(identity: usize) -> PyResult<Self> {
+        let already_active =
+            ACTIVE_COMPUTES.with(|active| !active.borrow_mut().insert(identity));
+        if already_active {
+            return Err(PyRuntimeError::new_err(
+                "ConcurrentDict callback must not re-enter the same dict",
+            ));
+        }
+        Ok(Self { identity })
+    }
+}
+
+impl Drop for ReentrancyGuard {
+    fn drop(&mut self) {
+        ACTIVE_COMPUTES.with(|active| {
+            active.borrow_mut().remove(&self.identity);
+        });
+    }
+}
+
+/// Rejects a call outright if this thread is already running a `compute`/`get_or_insert_with`/
+/// `par_*` callback for this same dict, *without* touching the DashMap. Plain accessors
+/// (`__getitem__`, `get`, `update`, ...) hold no `ReentrancyGuard` of their own — they're a
+/// single shard operation, not a callback-spanning one — but they must still refuse to run
+/// reentrantly, since attempting their own shard lock while this thread already holds one
+/// (from the enclosing callback) would deadlock rather than simply racing another thread.
+fn reject_reentrant(identity: usize) -> PyResult<()> {
+    let active = ACTIVE_COMPUTES.with(|active| active.borrow().contains(&identity));
+    if active {
+        Err(PyRuntimeError::new_err(
+            "ConcurrentDict callback must not re-enter the same dict",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 fn bound_to_object(value: &Bound<'_, PyAny>) -> PyObject {
     let py = value.py();
     unsafe {
@@ -22,9 +81,20 @@ fn none_object(py: Python<'_>) -> PyObject {
     py.None()
 }
 
+fn entry_as_tuple(py: Python<'_>, key: &PyKey, value: &PyObject) -> PyObject {
+    let key_object = key.object.clone_ref(py);
+    let value_object = value.clone_ref(py);
+    PyTuple::new(py, [key_object, value_object])
+        .expect("tuple construction from two owned objects cannot fail")
+        .unbind()
+        .into_any()
+}
+
 pub fn register(py: Python<'_>, parent: &Bound<'_, PyModule>) -> PyResult<()> {
     let module = PyModule::new(py, "dict")?;
     module.add_class::<ConcurrentDict>()?;
+    module.add_class::<DeleteMarker>()?;
+    module.add("DELETE", Py::new(py, DeleteMarker)?)?;
     parent.add_submodule(&module)?;
 
     let sys_modules: Bound<'_, pyo3::types::PyDict> =
@@ -33,6 +103,20 @@ pub fn register(py: Python<'_>, parent: &Bound<'_, PyModule>) -> PyResult<()> {
     Ok(())
 }
 
+/// Sentinel returned from a `compute` callback to request that the entry be removed.
+///
+/// A single instance is exposed as `syncx.dict.DELETE`; returning anything else from the
+/// callback is stored as the entry's new value.
+#[pyclass(module = "syncx.dict")]
+pub struct DeleteMarker;
+
+#[pymethods]
+impl DeleteMarker {
+    fn __repr__(&self) -> &'static str {
+        "DELETE"
+    }
+}
+
 #[pyclass(module = "syncx.dict")]
 pub struct ConcurrentDict {
     inner: Arc<DashMap<PyKey, PyObject>>,
@@ -97,6 +181,60 @@ impl ConcurrentDict {
     fn ensure_hashable(key: &Bound<'_, PyAny>) -> PyResult<PyKey> {
         PyKey::new(key)
     }
+
+    fn identity(&self) -> usize {
+        Arc::as_ptr(&self.inner) as usize
+    }
+
+    /// Runs `work` on a dedicated rayon pool sized to `max_threads` when given, or on the
+    /// global rayon pool otherwise.
+    fn with_pool<T: Send>(max_threads: Option<usize>, work: impl FnOnce() -> T + Send) -> T {
+        match max_threads {
+            Some(threads) if threads > 0 => rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .expect("failed to build rayon thread pool")
+                .install(work),
+            _ => work(),
+        }
+    }
+
+    /// Splits a snapshot of the dict's keys into roughly `max_threads` (or the global pool's
+    /// thread count) equally sized chunks, one per rayon task, so each task can
+    /// `Python::attach` once and then process its whole chunk under a single GIL hold
+    /// rather than re-attaching per key.
+    fn key_chunks(&self, max_threads: Option<usize>) -> Vec<Vec<PyKey>> {
+        let keys: Vec<PyKey> = self.inner.iter().map(|entry| entry.key().clone()).collect();
+        let chunk_count = max_threads
+            .filter(|&threads| threads > 0)
+            .unwrap_or_else(rayon::current_num_threads)
+            .max(1);
+        let chunk_size = (keys.len() + chunk_count - 1) / chunk_count.max(1);
+        let chunk_size = chunk_size.max(1);
+        keys.chunks(chunk_size)
+            .map(<[PyKey]>::to_vec)
+            .collect()
+    }
+}
+
+/// Iterates a snapshot of keys, values, or `(key, value)` pairs taken up front by
+/// `ConcurrentDict::__iter__`/`keys`/`values`/`items`. Backed by an owned `Vec` rather than
+/// a live `DashMap` iterator: holding a shard's read lock across Python callbacks invoked
+/// mid-iteration (e.g. via `for`) could deadlock against a concurrent writer to that shard.
+#[pyclass(module = "syncx.dict")]
+struct DictSnapshotIter {
+    items: std::vec::IntoIter<PyObject>,
+}
+
+#[pymethods]
+impl DictSnapshotIter {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<'_, Self>) -> Option<PyObject> {
+        slf.items.next()
+    }
 }
 
 #[pymethods]
@@ -108,20 +246,24 @@ impl ConcurrentDict {
         }
     }
 
-    fn __len__(&self) -> usize {
-        self.inner.len()
+    fn __len__(&self) -> PyResult<usize> {
+        reject_reentrant(self.identity())?;
+        Ok(self.inner.len())
     }
 
-    fn __bool__(&self) -> bool {
-        !self.inner.is_empty()
+    fn __bool__(&self) -> PyResult<bool> {
+        reject_reentrant(self.identity())?;
+        Ok(!self.inner.is_empty())
     }
 
     fn __contains__(&self, key: &Bound<'_, PyAny>) -> PyResult<bool> {
+        reject_reentrant(self.identity())?;
         let py_key = Self::ensure_hashable(key)?;
         Ok(self.inner.get(&py_key).is_some())
     }
 
     fn __getitem__(&self, py: Python<'_>, key: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        reject_reentrant(self.identity())?;
         let py_key = Self::ensure_hashable(key)?;
         if let Some(entry) = self.inner.get(&py_key) {
             Ok(entry.value().clone_ref(py))
@@ -131,6 +273,7 @@ impl ConcurrentDict {
     }
 
     fn __setitem__(&self, key: &Bound<'_, PyAny>, value: &Bound<'_, PyAny>) -> PyResult<()> {
+        reject_reentrant(self.identity())?;
         let py_key = Self::ensure_hashable(key)?;
         let value_object = bound_to_object(value);
         self.inner.insert(py_key, value_object);
@@ -138,6 +281,7 @@ impl ConcurrentDict {
     }
 
     fn __delitem__(&self, key: &Bound<'_, PyAny>) -> PyResult<()> {
+        reject_reentrant(self.identity())?;
         let py_key = Self::ensure_hashable(key)?;
         let key_object = bound_to_object(key);
         if self.inner.remove(&py_key).is_some() {
@@ -154,6 +298,7 @@ impl ConcurrentDict {
         key: &Bound<'_, PyAny>,
         default: Option<Bound<'_, PyAny>>,
     ) -> PyResult<PyObject> {
+        reject_reentrant(self.identity())?;
         let py_key = Self::ensure_hashable(key)?;
         if let Some(entry) = self.inner.get(&py_key) {
             Ok(entry.value().clone_ref(py))
@@ -171,6 +316,7 @@ impl ConcurrentDict {
         key: &Bound<'_, PyAny>,
         default: Option<Bound<'_, PyAny>>,
     ) -> PyResult<PyObject> {
+        reject_reentrant(self.identity())?;
         let py_key = Self::ensure_hashable(key)?;
         let entry = self.inner.entry(py_key);
         Ok(match entry {
@@ -187,6 +333,7 @@ impl ConcurrentDict {
 
     #[pyo3(signature = (key, default=None))]
     fn pop(&self, key: &Bound<'_, PyAny>, default: Option<Bound<'_, PyAny>>) -> PyResult<PyObject> {
+        reject_reentrant(self.identity())?;
         let py_key = Self::ensure_hashable(key)?;
         if let Some((_, value)) = self.inner.remove(&py_key) {
             Ok(value)
@@ -197,7 +344,418 @@ impl ConcurrentDict {
         }
     }
 
-    fn clear(&self) {
+    fn clear(&self) -> PyResult<()> {
+        reject_reentrant(self.identity())?;
         self.inner.clear();
+        Ok(())
+    }
+
+    /// Atomically read-modify-write the value stored at `key`.
+    ///
+    /// `fn_` is called with the current value (or `default` if the key is absent) while
+    /// the DashMap shard holding `key` stays locked, so the read and the write happen as
+    /// one atomic step. Returning `DELETE` (`syncx.dict.DELETE`) removes the entry instead
+    /// of storing a value.
+    ///
+    /// The callback must not call back into this same `ConcurrentDict` (even via another
+    /// key) — that would try to lock a shard this thread may already hold and deadlock.
+    /// Doing so raises `RuntimeError` instead. If the callback raises, the entry is left
+    /// unchanged and the exception propagates.
+    #[pyo3(signature = (key, fn_, default=None))]
+    fn compute(
+        &self,
+        py: Python<'_>,
+        key: &Bound<'_, PyAny>,
+        fn_: &Bound<'_, PyAny>,
+        default: Option<Bound<'_, PyAny>>,
+    ) -> PyResult<PyObject> {
+        let py_key = Self::ensure_hashable(key)?;
+        // Entered *before* `entry()` attempts the shard lock: a reentrant call targeting
+        // the same shard would otherwise block on a lock this thread already holds (from
+        // the enclosing `entry()` call below) and deadlock instead of raising.
+        let _guard = ReentrancyGuard::enter(self.identity())?;
+        match self.inner.entry(py_key) {
+            dashmap::mapref::entry::Entry::Occupied(mut occupied) => {
+                let current = occupied.get().clone_ref(py);
+                let result = fn_.call1((current,))?;
+                if result.is_instance_of::<DeleteMarker>() {
+                    occupied.remove();
+                    Ok(none_object(py))
+                } else {
+                    let result_object = bound_to_object(&result);
+                    occupied.insert(result_object.clone_ref(py));
+                    Ok(result_object)
+                }
+            }
+            dashmap::mapref::entry::Entry::Vacant(vacant) => {
+                let default_value = default
+                    .map(|d| bound_to_object(&d))
+                    .unwrap_or_else(|| none_object(py));
+                let result = fn_.call1((default_value,))?;
+                if result.is_instance_of::<DeleteMarker>() {
+                    Ok(none_object(py))
+                } else {
+                    let result_object = bound_to_object(&result);
+                    vacant.insert(result_object.clone_ref(py));
+                    Ok(result_object)
+                }
+            }
+        }
+    }
+
+    /// Returns the value stored at `key`, calling `factory()` (with no arguments) to
+    /// produce and insert one only if the key is absent. `factory` runs while the shard
+    /// stays locked, so — like `compute` — it must not call back into this same dict.
+    fn get_or_insert_with(
+        &self,
+        py: Python<'_>,
+        key: &Bound<'_, PyAny>,
+        factory: &Bound<'_, PyAny>,
+    ) -> PyResult<PyObject> {
+        let py_key = Self::ensure_hashable(key)?;
+        // See `compute` for why this must be entered before `entry()` rather than after.
+        let _guard = ReentrancyGuard::enter(self.identity())?;
+        match self.inner.entry(py_key) {
+            dashmap::mapref::entry::Entry::Occupied(occupied) => Ok(occupied.get().clone_ref(py)),
+            dashmap::mapref::entry::Entry::Vacant(vacant) => {
+                let value = factory.call0()?;
+                let value_object = bound_to_object(&value);
+                vacant.insert(value_object.clone_ref(py));
+                Ok(value_object)
+            }
+        }
+    }
+
+    /// Atomically add `delta` to the integer stored at `key`, inserting `default` first
+    /// if the key is absent. Performs the same entry-locked read-modify-write as `compute`,
+    /// specialised to the common concurrent-counter pattern (`d[k] = d[k] + 1`), which is otherwise racy because the
+    /// get and the set are two separate shard-lock acquisitions.
+    #[pyo3(signature = (key, delta=1, default=0))]
+    fn increment(
+        &self,
+        py: Python<'_>,
+        key: &Bound<'_, PyAny>,
+        delta: i64,
+        default: i64,
+    ) -> PyResult<PyObject> {
+        reject_reentrant(self.identity())?;
+        let py_key = Self::ensure_hashable(key)?;
+        let updated = match self.inner.entry(py_key) {
+            dashmap::mapref::entry::Entry::Occupied(mut occupied) => {
+                let current = occupied.get().bind(py).clone();
+                let next = current.add(delta)?.unbind();
+                occupied.insert(next.clone_ref(py));
+                next
+            }
+            dashmap::mapref::entry::Entry::Vacant(vacant) => {
+                let next = (default + delta).into_pyobject(py)?.unbind().into_any();
+                vacant.insert(next.clone_ref(py));
+                next
+            }
+        };
+        Ok(updated)
+    }
+
+    fn __iter__(&self, py: Python<'_>) -> PyResult<Py<DictSnapshotIter>> {
+        self.keys(py)
+    }
+
+    /// Snapshot of the dict's keys, taken under each shard's lock at call time. Mutations
+    /// made after this call (even by the same thread) are not reflected.
+    fn keys(&self, py: Python<'_>) -> PyResult<Py<DictSnapshotIter>> {
+        reject_reentrant(self.identity())?;
+        let keys: Vec<PyObject> = self
+            .inner
+            .iter()
+            .map(|entry| entry.key().object.clone_ref(py))
+            .collect();
+        Py::new(py, DictSnapshotIter { items: keys.into_iter() })
+    }
+
+    /// Snapshot of the dict's values, taken under each shard's lock at call time.
+    fn values(&self, py: Python<'_>) -> PyResult<Py<DictSnapshotIter>> {
+        reject_reentrant(self.identity())?;
+        let values: Vec<PyObject> = self
+            .inner
+            .iter()
+            .map(|entry| entry.value().clone_ref(py))
+            .collect();
+        Py::new(py, DictSnapshotIter { items: values.into_iter() })
+    }
+
+    /// Snapshot of `(key, value)` pairs, taken under each shard's lock at call time.
+    fn items(&self, py: Python<'_>) -> PyResult<Py<DictSnapshotIter>> {
+        reject_reentrant(self.identity())?;
+        let items: Vec<PyObject> = self
+            .inner
+            .iter()
+            .map(|entry| {
+                let key = entry.key().object.clone_ref(py);
+                let value = entry.value().clone_ref(py);
+                PyTuple::new(py, [key, value])
+                    .expect("tuple construction from two owned objects cannot fail")
+                    .unbind()
+                    .into_any()
+            })
+            .collect();
+        Py::new(py, DictSnapshotIter { items: items.into_iter() })
+    }
+
+    /// Inserts every pair from `other`, which may be another `ConcurrentDict`, a `dict`, or
+    /// any iterable of `(key, value)` pairs.
+    fn update(&self, other: &Bound<'_, PyAny>) -> PyResult<()> {
+        reject_reentrant(self.identity())?;
+        if let Ok(other_dict) = other.extract::<PyRef<'_, ConcurrentDict>>() {
+            let py = other.py();
+            for entry in other_dict.inner.iter() {
+                let key = Self::ensure_hashable(entry.key().object.bind(py))?;
+                self.inner.insert(key, entry.value().clone_ref(py));
+            }
+            return Ok(());
+        }
+        if let Ok(plain_dict) = other.downcast::<PyDict>() {
+            for (key, value) in plain_dict.iter() {
+                let py_key = Self::ensure_hashable(&key)?;
+                self.inner.insert(py_key, bound_to_object(&value));
+            }
+            return Ok(());
+        }
+        for pair in other.try_iter()? {
+            let pair = pair?;
+            let key = pair.get_item(0)?;
+            let value = pair.get_item(1)?;
+            let py_key = Self::ensure_hashable(&key)?;
+            self.inner.insert(py_key, bound_to_object(&value));
+        }
+        Ok(())
+    }
+
+    /// Returns a plain `dict` copy of the current contents, taken under each shard's lock
+    /// at call time.
+    fn snapshot<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        reject_reentrant(self.identity())?;
+        let result = PyDict::new(py);
+        for entry in self.inner.iter() {
+            result.set_item(entry.key().object.bind(py), entry.value().bind(py))?;
+        }
+        Ok(result)
+    }
+
+    /// Removes every entry for which `predicate(key, value)` returns false.
+    ///
+    /// For maps at or above an internal size threshold, the dict's keys are split into
+    /// chunks and evaluated across a rayon thread pool, each task re-attaching the GIL
+    /// once via `Python::attach` rather than per key; smaller maps run sequentially on the
+    /// caller's own GIL hold. `max_threads`, if given, caps (and sizes) the pool used.
+    ///
+    /// Like `compute`, each entry's shard stays locked for the duration of the call to
+    /// `predicate`, so — on whichever thread evaluates it — `predicate` must not call back
+    /// into this same dict; doing so raises `RuntimeError` instead of deadlocking.
+    #[pyo3(signature = (predicate, max_threads=None))]
+    fn par_filter(
+        &self,
+        py: Python<'_>,
+        predicate: &Bound<'_, PyAny>,
+        max_threads: Option<usize>,
+    ) -> PyResult<()> {
+        let identity = self.identity();
+        // Entered before even the size check below touches a shard, for the same reason
+        // `compute` enters its guard before `entry()`: a reentrant call must be rejected
+        // before this thread attempts any lock it might already hold, not after.
+        let guard = ReentrancyGuard::enter(identity)?;
+        if self.inner.len() < PAR_SEQUENTIAL_THRESHOLD {
+            let mut stale = Vec::new();
+            for entry in self.inner.iter() {
+                let keep = predicate
+                    .call1((entry.key().object.bind(py), entry.value().bind(py)))?
+                    .is_truthy()?;
+                if !keep {
+                    stale.push(entry.key().clone());
+                }
+            }
+            drop(guard);
+            for key in stale {
+                self.inner.remove(&key);
+            }
+            return Ok(());
+        }
+
+        let predicate_object = predicate.clone().unbind();
+        let inner = Arc::clone(&self.inner);
+        let chunks = self.key_chunks(max_threads);
+
+        let stale: Vec<PyKey> = py.detach(|| {
+            Self::with_pool(max_threads, || {
+                chunks
+                    .into_par_iter()
+                    .map(|chunk| -> PyResult<Vec<PyKey>> {
+                        Python::attach(|py| {
+                            let predicate = predicate_object.bind(py);
+                            let guard = ReentrancyGuard::enter(identity)?;
+                            let mut stale_in_chunk = Vec::new();
+                            for key in chunk {
+                                if let Some(entry) = inner.get(&key) {
+                                    let keep = predicate
+                                        .call1((key.object.bind(py), entry.value().bind(py)))?
+                                        .is_truthy()?;
+                                    if !keep {
+                                        stale_in_chunk.push(key.clone());
+                                    }
+                                }
+                            }
+                            drop(guard);
+                            Ok(stale_in_chunk)
+                        })
+                    })
+                    .collect::<PyResult<Vec<Vec<PyKey>>>>()
+            })
+        })?
+        .into_iter()
+        .flatten()
+        .collect();
+
+        for key in stale {
+            self.inner.remove(&key);
+        }
+        Ok(())
+    }
+
+    /// Replaces every value `v` at each key with `fn_(v)`, in place.
+    ///
+    /// Follows the same chunked rayon / sequential-fallback strategy as `par_filter`; see
+    /// that method's docs for the threading model and the reentrancy restriction on `fn_`.
+    #[pyo3(signature = (fn_, max_threads=None))]
+    fn par_map_values(
+        &self,
+        py: Python<'_>,
+        fn_: &Bound<'_, PyAny>,
+        max_threads: Option<usize>,
+    ) -> PyResult<()> {
+        let identity = self.identity();
+        // See `par_filter` for why this is entered before the size check rather than after.
+        let guard = ReentrancyGuard::enter(identity)?;
+        if self.inner.len() < PAR_SEQUENTIAL_THRESHOLD {
+            for mut entry in self.inner.iter_mut() {
+                let current = entry.value().clone_ref(py);
+                let updated = fn_.call1((current,))?;
+                *entry.value_mut() = bound_to_object(&updated);
+            }
+            drop(guard);
+            return Ok(());
+        }
+
+        let fn_object = fn_.clone().unbind();
+        let inner = Arc::clone(&self.inner);
+        let chunks = self.key_chunks(max_threads);
+
+        py.detach(|| {
+            Self::with_pool(max_threads, || {
+                chunks
+                    .into_par_iter()
+                    .try_for_each(|chunk| -> PyResult<()> {
+                        Python::attach(|py| {
+                            let fn_ = fn_object.bind(py);
+                            let guard = ReentrancyGuard::enter(identity)?;
+                            for key in chunk {
+                                if let Some(mut entry) = inner.get_mut(&key) {
+                                    let current = entry.value().clone_ref(py);
+                                    let updated = fn_.call1((current,))?;
+                                    *entry.value_mut() = bound_to_object(&updated);
+                                }
+                            }
+                            drop(guard);
+                            Ok(())
+                        })
+                    })
+            })
+        })
+    }
+
+    /// Folds `fn_(accumulator, (key, value))` over every entry, seeded with `initial`.
+    ///
+    /// For large maps each chunk is first folded independently (starting again from
+    /// `initial`) across the rayon pool, then the per-chunk partial results are combined
+    /// by feeding each one back through `fn_` as if it were a single `(key, value)` item;
+    /// this only gives the same answer as a sequential reduce when `fn_` is associative
+    /// and `initial` is its identity (true of the typical sum/product/merge use cases this
+    /// is meant for). Smaller maps reduce sequentially instead, which has no such
+    /// restriction.
+    ///
+    /// As with `par_filter`/`par_map_values`, an entry's shard stays locked while `fn_` runs
+    /// for it, so `fn_` must not call back into this same dict.
+    #[pyo3(signature = (fn_, initial, max_threads=None))]
+    fn par_reduce(
+        &self,
+        py: Python<'_>,
+        fn_: &Bound<'_, PyAny>,
+        initial: &Bound<'_, PyAny>,
+        max_threads: Option<usize>,
+    ) -> PyResult<PyObject> {
+        let identity = self.identity();
+        // See `par_filter` for why this is entered before the size check rather than after.
+        let guard = ReentrancyGuard::enter(identity)?;
+        if self.inner.len() < PAR_SEQUENTIAL_THRESHOLD {
+            let mut accumulator = bound_to_object(initial);
+            for entry in self.inner.iter() {
+                let item = entry_as_tuple(py, entry.key(), entry.value());
+                accumulator = fn_.call1((accumulator, item))?.unbind();
+            }
+            drop(guard);
+            return Ok(accumulator);
+        }
+
+        let fn_object = fn_.clone().unbind();
+        let initial_object = bound_to_object(initial);
+        let inner = Arc::clone(&self.inner);
+        let chunks = self.key_chunks(max_threads);
+
+        let partials: Vec<PyObject> = py.detach(|| {
+            Self::with_pool(max_threads, || {
+                chunks
+                    .into_par_iter()
+                    .map(|chunk| -> PyResult<PyObject> {
+                        Python::attach(|py| {
+                            let fn_ = fn_object.bind(py);
+                            let guard = ReentrancyGuard::enter(identity)?;
+                            let mut accumulator = initial_object.clone_ref(py);
+                            for key in chunk {
+                                if let Some(entry) = inner.get(&key) {
+                                    let item = entry_as_tuple(py, &key, entry.value());
+                                    accumulator = fn_.call1((accumulator, item))?.unbind();
+                                }
+                            }
+                            drop(guard);
+                            Ok(accumulator)
+                        })
+                    })
+                    .collect::<PyResult<Vec<PyObject>>>()
+            })
+        })?;
+
+        let mut accumulator = initial_object;
+        for partial in partials {
+            accumulator = fn_.call1((accumulator, partial))?.unbind();
+        }
+        Ok(accumulator)
+    }
+
+    /// Equality against a plain `dict` (same keys and values); any other type compares
+    /// unequal rather than raising.
+    fn __eq__(&self, py: Python<'_>, other: &Bound<'_, PyAny>) -> PyResult<bool> {
+        reject_reentrant(self.identity())?;
+        let Ok(other_dict) = other.downcast::<PyDict>() else {
+            return Ok(false);
+        };
+        if other_dict.len() != self.inner.len() {
+            return Ok(false);
+        }
+        for entry in self.inner.iter() {
+            let key = entry.key().object.bind(py);
+            match other_dict.get_item(key)? {
+                Some(value) if value.eq(entry.value().bind(py))? => {}
+                _ => return Ok(false),
+            }
+        }
+        Ok(true)
     }
 }
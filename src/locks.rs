@@ -1,15 +1,19 @@
 use crate::submodule;
 use parking_lot::lock_api::{
     RawMutex as RawMutexTrait, RawMutexTimed, RawRwLock as RawRwLockTrait, RawRwLockDowngrade,
-    RawRwLockFair, RawRwLockTimed,
+    RawRwLockFair, RawRwLockTimed, RawRwLockUpgrade, RawRwLockUpgradeDowngrade,
+    RawRwLockUpgradeTimed,
 };
-use parking_lot::{RawMutex, RawRwLock, ReentrantMutex, ReentrantMutexGuard};
+use parking_lot::{Condvar, Mutex, MutexGuard, RawMutex, RawRwLock, ReentrantMutex, ReentrantMutexGuard};
 use pyo3::conversion::IntoPyObject;
+use pyo3::exceptions::{PyException, PyRuntimeError};
 use pyo3::prelude::*;
 use pyo3::types::PyAny;
 use pyo3::Bound;
+use std::cell::UnsafeCell;
 use std::mem::transmute;
 use std::ptr::NonNull;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
@@ -145,6 +149,50 @@ fn lock_exclusive_with_options(
     }
 }
 
+#[allow(deprecated)]
+fn lock_upgradable_with_options(
+    inner: &RawRwLock,
+    py: Python<'_>,
+    blocking: bool,
+    timeout: Option<f64>,
+) -> PyResult<bool> {
+    if !blocking {
+        return Ok(inner.try_lock_upgradable());
+    }
+
+    if inner.try_lock_upgradable() {
+        return Ok(true);
+    }
+
+    match timeout {
+        None => {
+            py.allow_threads(|| inner.lock_upgradable());
+            Ok(true)
+        }
+        Some(value) => {
+            if value.is_sign_negative() {
+                return Ok(false);
+            }
+            if !value.is_finite() {
+                py.allow_threads(|| inner.lock_upgradable());
+                return Ok(true);
+            }
+
+            let max_secs = Duration::MAX.as_secs_f64();
+            if value >= max_secs {
+                py.allow_threads(|| inner.lock_upgradable());
+                return Ok(true);
+            }
+
+            let duration = Duration::from_secs_f64(value);
+            let deadline = Instant::now()
+                .checked_add(duration)
+                .unwrap_or_else(Instant::now);
+            Ok(py.allow_threads(|| inner.try_lock_upgradable_until(deadline)))
+        }
+    }
+}
+
 pub fn register(py: Python<'_>, parent: &Bound<'_, PyModule>) -> PyResult<()> {
     let module = PyModule::new(py, "locks")?;
     module.add_class::<Lock>()?;
@@ -154,26 +202,87 @@ pub fn register(py: Python<'_>, parent: &Bound<'_, PyModule>) -> PyResult<()> {
     module.add_class::<RWLock>()?;
     module.add_class::<ReadGuard>()?;
     module.add_class::<WriteGuard>()?;
+    module.add_class::<UpgradableGuard>()?;
+    module.add_class::<Condition>()?;
+    module.add_class::<PoisonError>()?;
     submodule::register_submodule(py, parent, &module, "syncx.locks")?;
     Ok(())
 }
 
+/// Raised by a `poison=True` `Lock`/`RWLock` once a holder's critical section raised while
+/// the lock was held, mirroring the poison-on-unwind discipline of Rust's std locks. The
+/// `guard` attribute is populated whenever the failing acquire already produced a guard
+/// object, letting the catcher still reach the (possibly inconsistent) protected state
+/// before deciding whether to `clear_poison()` and carry on.
+#[pyclass(module = "syncx.locks", extends = PyException)]
+pub struct PoisonError {
+    #[pyo3(get, set)]
+    guard: Option<Py<PyAny>>,
+}
+
+#[pymethods]
+impl PoisonError {
+    #[new]
+    #[pyo3(signature = (message=None))]
+    fn new(message: Option<String>) -> Self {
+        let _ = message;
+        Self { guard: None }
+    }
+}
+
+/// Construct a `PoisonError` through its real Python `__new__` (so `str(err)` reflects
+/// `message` the same way every other exception's `.args` does), then attach `guard`
+/// afterwards since it isn't part of the constructor's public signature.
+fn poison_error(py: Python<'_>, message: &str, guard: Option<Py<PyAny>>) -> PyErr {
+    let cls = py.get_type::<PoisonError>();
+    match cls.call1((message,)) {
+        Ok(instance) => {
+            if let Some(guard) = guard {
+                let _ = instance.setattr("guard", guard);
+            }
+            PyErr::from_value(instance)
+        }
+        Err(err) => err,
+    }
+}
+
 #[pyclass(module = "syncx.locks")]
 pub struct Lock {
     inner: RawMutex,
+    poison_enabled: bool,
+    poisoned: AtomicBool,
+}
+
+impl Lock {
+    fn poison_check(&self, py: Python<'_>) -> PyResult<()> {
+        if self.poison_enabled && self.poisoned.load(Ordering::Acquire) {
+            return Err(poison_error(py, "lock is poisoned", None));
+        }
+        Ok(())
+    }
+
+    fn mark_poisoned(&self) {
+        if self.poison_enabled {
+            self.poisoned.store(true, Ordering::Release);
+        }
+    }
 }
 
 #[pymethods]
 impl Lock {
     #[new]
-    fn new() -> Self {
+    #[pyo3(signature = (poison=false))]
+    fn new(poison: bool) -> Self {
         Self {
             inner: RawMutex::INIT,
+            poison_enabled: poison,
+            poisoned: AtomicBool::new(false),
         }
     }
 
     #[pyo3(signature = (blocking=true, timeout=None))]
     pub fn acquire(&self, py: Python<'_>, blocking: bool, timeout: Option<f64>) -> PyResult<bool> {
+        self.poison_check(py)?;
         lock_with_options(&self.inner, py, blocking, timeout)
     }
 
@@ -210,6 +319,14 @@ impl Lock {
         self.locked()
     }
 
+    pub fn is_poisoned(&self) -> bool {
+        self.poison_enabled && self.poisoned.load(Ordering::Acquire)
+    }
+
+    pub fn clear_poison(&self) {
+        self.poisoned.store(false, Ordering::Release);
+    }
+
     #[pyo3(signature = (blocking=true, timeout=None))]
     pub fn guard<'py>(
         slf: PyRef<'py, Self>,
@@ -220,9 +337,15 @@ impl Lock {
         if !lock_with_options(&slf.inner, py, blocking, timeout)? {
             return Ok(None);
         }
+        let poisoned = slf.poison_enabled && slf.poisoned.load(Ordering::Acquire);
         let ptr = NonNull::from(&slf.inner);
         let owner = slf.into_pyobject(py)?.unbind().into_any();
-        Ok(Some(LockGuard::new(owner, ptr)))
+        let guard = LockGuard::new(owner, ptr);
+        if poisoned {
+            let guard_object = Py::new(py, guard)?.into_any();
+            return Err(poison_error(py, "lock is poisoned", Some(guard_object)));
+        }
+        Ok(Some(guard))
     }
 
     fn __enter__<'py>(slf: PyRef<'py, Self>, py: Python<'py>) -> PyResult<PyRef<'py, Self>> {
@@ -232,10 +355,13 @@ impl Lock {
 
     fn __exit__(
         &self,
-        _exc_type: &Bound<'_, PyAny>,
+        exc_type: &Bound<'_, PyAny>,
         _exc: &Bound<'_, PyAny>,
         _tb: &Bound<'_, PyAny>,
     ) -> PyResult<bool> {
+        if !exc_type.is_none() {
+            self.mark_poisoned();
+        }
         self.release();
         Ok(false)
     }
@@ -284,10 +410,15 @@ impl LockGuard {
 
     fn __exit__(
         &mut self,
-        _exc_type: &Bound<'_, PyAny>,
+        exc_type: &Bound<'_, PyAny>,
         _exc: &Bound<'_, PyAny>,
         _tb: &Bound<'_, PyAny>,
     ) -> PyResult<bool> {
+        if !exc_type.is_none()
+            && let Ok(lock) = self._owner.bind(exc_type.py()).downcast::<Lock>()
+        {
+            lock.borrow().mark_poisoned();
+        }
         self.release();
         Ok(false)
     }
@@ -402,17 +533,45 @@ impl Drop for RLockGuard {
 #[pyclass(module = "syncx.locks")]
 pub struct RWLock {
     inner: RawRwLock,
+    poison_enabled: bool,
+    poisoned: AtomicBool,
+}
+
+impl RWLock {
+    fn poison_check(&self, py: Python<'_>) -> PyResult<()> {
+        if self.poison_enabled && self.poisoned.load(Ordering::Acquire) {
+            return Err(poison_error(py, "lock is poisoned", None));
+        }
+        Ok(())
+    }
+
+    fn mark_poisoned(&self) {
+        if self.poison_enabled {
+            self.poisoned.store(true, Ordering::Release);
+        }
+    }
 }
 
 #[pymethods]
 impl RWLock {
     #[new]
-    fn new() -> Self {
+    #[pyo3(signature = (poison=false))]
+    fn new(poison: bool) -> Self {
         Self {
             inner: RawRwLock::INIT,
+            poison_enabled: poison,
+            poisoned: AtomicBool::new(false),
         }
     }
 
+    pub fn is_poisoned(&self) -> bool {
+        self.poison_enabled && self.poisoned.load(Ordering::Acquire)
+    }
+
+    pub fn clear_poison(&self) {
+        self.poisoned.store(false, Ordering::Release);
+    }
+
     #[pyo3(signature = (blocking=true, timeout=None))]
     pub fn acquire_read(
         &self,
@@ -420,6 +579,7 @@ impl RWLock {
         blocking: bool,
         timeout: Option<f64>,
     ) -> PyResult<bool> {
+        self.poison_check(py)?;
         lock_shared_with_options(&self.inner, py, blocking, timeout)
     }
 
@@ -458,9 +618,15 @@ impl RWLock {
         if !lock_shared_with_options(&slf.inner, py, blocking, timeout)? {
             return Ok(None);
         }
+        let poisoned = slf.poison_enabled && slf.poisoned.load(Ordering::Acquire);
         let ptr = NonNull::from(&slf.inner);
         let owner = slf.into_pyobject(py)?.unbind().into_any();
-        Ok(Some(ReadGuard::new(owner, ptr)))
+        let guard = ReadGuard::new(owner, ptr);
+        if poisoned {
+            let guard_object = Py::new(py, guard)?.into_any();
+            return Err(poison_error(py, "lock is poisoned", Some(guard_object)));
+        }
+        Ok(Some(guard))
     }
 
     #[pyo3(signature = (blocking=true, timeout=None))]
@@ -470,6 +636,7 @@ impl RWLock {
         blocking: bool,
         timeout: Option<f64>,
     ) -> PyResult<bool> {
+        self.poison_check(py)?;
         lock_exclusive_with_options(&self.inner, py, blocking, timeout)
     }
 
@@ -499,9 +666,15 @@ impl RWLock {
         if !lock_exclusive_with_options(&slf.inner, py, blocking, timeout)? {
             return Ok(None);
         }
+        let poisoned = slf.poison_enabled && slf.poisoned.load(Ordering::Acquire);
         let ptr = NonNull::from(&slf.inner);
         let owner = slf.into_pyobject(py)?.unbind().into_any();
-        Ok(Some(WriteGuard::new(owner, ptr)))
+        let guard = WriteGuard::new(owner, ptr);
+        if poisoned {
+            let guard_object = Py::new(py, guard)?.into_any();
+            return Err(poison_error(py, "lock is poisoned", Some(guard_object)));
+        }
+        Ok(Some(guard))
     }
 
     pub fn try_acquire_write(&self) -> bool {
@@ -538,6 +711,48 @@ impl RWLock {
             self.inner.bump_exclusive();
         }
     }
+
+    #[pyo3(signature = (blocking=true, timeout=None))]
+    pub fn acquire_upgradable(
+        &self,
+        py: Python<'_>,
+        blocking: bool,
+        timeout: Option<f64>,
+    ) -> PyResult<bool> {
+        self.poison_check(py)?;
+        lock_upgradable_with_options(&self.inner, py, blocking, timeout)
+    }
+
+    pub fn try_acquire_upgradable(&self) -> bool {
+        self.inner.try_lock_upgradable()
+    }
+
+    pub fn upgradable_release(&self) {
+        unsafe {
+            self.inner.unlock_upgradable();
+        }
+    }
+
+    #[pyo3(signature = (blocking=true, timeout=None))]
+    pub fn upgradable_guard<'py>(
+        slf: PyRef<'py, Self>,
+        py: Python<'py>,
+        blocking: bool,
+        timeout: Option<f64>,
+    ) -> PyResult<Option<UpgradableGuard>> {
+        if !lock_upgradable_with_options(&slf.inner, py, blocking, timeout)? {
+            return Ok(None);
+        }
+        let poisoned = slf.poison_enabled && slf.poisoned.load(Ordering::Acquire);
+        let ptr = NonNull::from(&slf.inner);
+        let owner = slf.into_pyobject(py)?.unbind().into_any();
+        let guard = UpgradableGuard::new(owner, ptr);
+        if poisoned {
+            let guard_object = Py::new(py, guard)?.into_any();
+            return Err(poison_error(py, "lock is poisoned", Some(guard_object)));
+        }
+        Ok(Some(guard))
+    }
 }
 
 #[pyclass(module = "syncx.locks", unsendable, freelist = 4096)]
@@ -583,10 +798,15 @@ impl ReadGuard {
 
     fn __exit__(
         &mut self,
-        _exc_type: &Bound<'_, PyAny>,
+        exc_type: &Bound<'_, PyAny>,
         _exc: &Bound<'_, PyAny>,
         _tb: &Bound<'_, PyAny>,
     ) -> PyResult<bool> {
+        if !exc_type.is_none()
+            && let Ok(lock) = self._owner.bind(exc_type.py()).downcast::<RWLock>()
+        {
+            lock.borrow().mark_poisoned();
+        }
         self.release();
         Ok(false)
     }
@@ -671,10 +891,15 @@ impl WriteGuard {
 
     fn __exit__(
         &mut self,
-        _exc_type: &Bound<'_, PyAny>,
+        exc_type: &Bound<'_, PyAny>,
         _exc: &Bound<'_, PyAny>,
         _tb: &Bound<'_, PyAny>,
     ) -> PyResult<bool> {
+        if !exc_type.is_none()
+            && let Ok(lock) = self._owner.bind(exc_type.py()).downcast::<RWLock>()
+        {
+            lock.borrow().mark_poisoned();
+        }
         self.release();
         Ok(false)
     }
@@ -685,3 +910,310 @@ impl Drop for WriteGuard {
         self.unlock_raw();
     }
 }
+
+/// Holds `RWLock` in upgradable-read mode: coexists with ordinary readers but excludes
+/// other upgradable/writer holders, letting a reader check a condition and then commit to
+/// a write without racing other writers for the exclusive lock in between.
+#[pyclass(module = "syncx.locks", unsendable, freelist = 4096)]
+pub struct UpgradableGuard {
+    _owner: Py<PyAny>,
+    ptr: NonNull<RawRwLock>,
+    held: bool,
+}
+
+impl UpgradableGuard {
+    fn new(owner: Py<PyAny>, ptr: NonNull<RawRwLock>) -> Self {
+        Self {
+            _owner: owner,
+            ptr,
+            held: true,
+        }
+    }
+
+    fn unlock_raw(&mut self) {
+        if self.held {
+            unsafe {
+                self.ptr.as_ref().unlock_upgradable();
+            }
+            self.held = false;
+        }
+    }
+}
+
+#[pymethods]
+impl UpgradableGuard {
+    pub fn release(&mut self) {
+        self.unlock_raw();
+    }
+
+    #[pyo3(name = "unlock")]
+    pub fn unlock_alias(&mut self) {
+        self.release();
+    }
+
+    /// Block until every other reader has released its shared lock, then convert this
+    /// guard into a `WriteGuard`. No other upgradable or write holder can exist while this
+    /// guard is held, so only in-flight readers are waited for.
+    #[allow(deprecated)]
+    pub fn upgrade(&mut self, py: Python<'_>) -> Option<WriteGuard> {
+        if !self.held {
+            return None;
+        }
+        let inner: &RawRwLock = unsafe { self.ptr.as_ref() };
+        py.allow_threads(|| unsafe { inner.upgrade() });
+        self.held = false;
+        let owner = self._owner.clone_ref(py);
+        Some(WriteGuard::new(owner, self.ptr))
+    }
+
+    pub fn try_upgrade(&mut self, py: Python<'_>) -> Option<WriteGuard> {
+        if !self.held {
+            return None;
+        }
+        if unsafe { self.ptr.as_ref().try_upgrade() } {
+            self.held = false;
+            let owner = self._owner.clone_ref(py);
+            Some(WriteGuard::new(owner, self.ptr))
+        } else {
+            None
+        }
+    }
+
+    pub fn downgrade(&mut self, py: Python<'_>) -> Option<ReadGuard> {
+        if !self.held {
+            return None;
+        }
+        unsafe {
+            self.ptr.as_ref().downgrade_upgradable();
+        }
+        self.held = false;
+        let owner = self._owner.clone_ref(py);
+        Some(ReadGuard::new(owner, self.ptr))
+    }
+
+    fn __enter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __exit__(
+        &mut self,
+        exc_type: &Bound<'_, PyAny>,
+        _exc: &Bound<'_, PyAny>,
+        _tb: &Bound<'_, PyAny>,
+    ) -> PyResult<bool> {
+        if !exc_type.is_none()
+            && let Ok(lock) = self._owner.bind(exc_type.py()).downcast::<RWLock>()
+        {
+            lock.borrow().mark_poisoned();
+        }
+        self.release();
+        Ok(false)
+    }
+}
+
+impl Drop for UpgradableGuard {
+    fn drop(&mut self) {
+        self.unlock_raw();
+    }
+}
+
+/// A `threading.Condition`-style wait/notify primitive bound to its own lock.
+///
+/// Unlike `Lock`, which holds a bare `RawMutex`, `Condition` needs a typed
+/// `parking_lot::Mutex<()>` because `Condvar::wait`/`wait_for` take a real `MutexGuard`.
+/// Acquire the condition with `with cond:` (mirroring `Lock`'s `__enter__`/`__exit__`),
+/// then call `wait`/`wait_for` to release the lock and park until `notify`/`notify_all`
+/// wakes this thread, re-acquiring the lock before returning — exactly like the stdlib.
+#[pyclass(module = "syncx.locks")]
+pub struct Condition {
+    mutex: Mutex<()>,
+    condvar: Condvar,
+    held: UnsafeCell<Option<MutexGuard<'static, ()>>>,
+}
+
+// SAFETY: `held` is only ever populated while this thread holds `mutex` (between
+// `__enter__`/`wait` and the matching `__exit__`), and `mutex` itself serializes access
+// to that slot across threads, so treating `Condition` as `Send + Sync` is sound even
+// though `MutexGuard` itself is `!Send`.
+unsafe impl Send for Condition {}
+unsafe impl Sync for Condition {}
+
+/// `parking_lot::MutexGuard` is `!Send` by design, which would otherwise stop it from
+/// crossing the closure boundary of `Python::allow_threads`. That closure only ever runs
+/// synchronously on the calling thread while the GIL is released, so the guard never
+/// actually moves between threads here, making this wrapper sound.
+struct SendGuard<'a>(MutexGuard<'a, ()>);
+
+unsafe impl Send for SendGuard<'_> {}
+
+impl Condition {
+    fn take_guard(&self) -> PyResult<MutexGuard<'static, ()>> {
+        unsafe { &mut *self.held.get() }
+            .take()
+            .ok_or_else(|| PyRuntimeError::new_err("cannot wait on an unheld Condition"))
+    }
+
+    fn store_guard(&self, guard: MutexGuard<'static, ()>) {
+        unsafe {
+            *self.held.get() = Some(guard);
+        }
+    }
+
+    fn is_held(&self) -> bool {
+        unsafe { &*self.held.get() }.is_some()
+    }
+
+    // Takes `&Mutex<()>` rather than `&self` so that `Python::allow_threads` only ever
+    // captures a plain, `Sync`-derived `Send` reference instead of `self` as a whole.
+    fn lock_mutex(mutex: &Mutex<()>) -> SendGuard<'_> {
+        SendGuard(mutex.lock())
+    }
+
+    // These take the whole `&mut SendGuard` rather than reaching into `guard.0` at the
+    // call site: closure capture analysis decomposes field accesses written inline, which
+    // would otherwise capture the inner (`!Send`) `MutexGuard` instead of the wrapper.
+    fn condvar_wait(condvar: &Condvar, guard: &mut SendGuard<'_>) {
+        condvar.wait(&mut guard.0);
+    }
+
+    fn condvar_wait_for(condvar: &Condvar, guard: &mut SendGuard<'_>, duration: Duration) -> bool {
+        condvar.wait_for(&mut guard.0, duration).timed_out()
+    }
+}
+
+#[pymethods]
+impl Condition {
+    #[new]
+    fn new() -> Self {
+        Self {
+            mutex: Mutex::new(()),
+            condvar: Condvar::new(),
+            held: UnsafeCell::new(None),
+        }
+    }
+
+    #[allow(deprecated)]
+    fn __enter__<'py>(slf: PyRef<'py, Self>, py: Python<'py>) -> PyRef<'py, Self> {
+        let mutex: &Mutex<()> = &slf.mutex;
+        let guard = py.allow_threads(|| Condition::lock_mutex(mutex)).0;
+        // SAFETY: `slf` (and therefore `slf.mutex`) is kept alive by the strong
+        // reference Python holds on the object for the duration of the `with` block.
+        let guard: MutexGuard<'static, ()> = unsafe { transmute(guard) };
+        slf.store_guard(guard);
+        slf
+    }
+
+    fn __exit__(
+        &self,
+        _exc_type: &Bound<'_, PyAny>,
+        _exc: &Bound<'_, PyAny>,
+        _tb: &Bound<'_, PyAny>,
+    ) -> PyResult<bool> {
+        drop(self.take_guard()?);
+        Ok(false)
+    }
+
+    /// Release the condition's lock and block until `notify`/`notify_all` wakes this
+    /// thread, then re-acquire the lock before returning. Must be called while holding
+    /// the condition (inside a `with cond:` block). Returns `False` if `timeout` elapsed
+    /// without a wakeup.
+    #[pyo3(signature = (timeout=None))]
+    #[allow(deprecated)]
+    fn wait(&self, py: Python<'_>, timeout: Option<f64>) -> PyResult<bool> {
+        let mut guard = SendGuard(self.take_guard()?);
+        let condvar = &self.condvar;
+
+        let woke = match timeout {
+            None => {
+                py.allow_threads(|| Condition::condvar_wait(condvar, &mut guard));
+                true
+            }
+            Some(value) if value.is_sign_negative() => false,
+            Some(value) if !value.is_finite() => {
+                py.allow_threads(|| Condition::condvar_wait(condvar, &mut guard));
+                true
+            }
+            Some(value) => {
+                let max_secs = Duration::MAX.as_secs_f64();
+                let duration = if value >= max_secs {
+                    Duration::MAX
+                } else {
+                    Duration::from_secs_f64(value)
+                };
+                let timed_out = py
+                    .allow_threads(|| Condition::condvar_wait_for(condvar, &mut guard, duration));
+                !timed_out
+            }
+        };
+
+        self.store_guard(guard.0);
+        Ok(woke)
+    }
+
+    /// Like `wait`, but loops (re-checking after every spurious or timed wakeup) until
+    /// `predicate()` returns truthy or `timeout` elapses, returning the predicate's final
+    /// truthiness — matching `threading.Condition.wait_for`.
+    #[pyo3(signature = (predicate, timeout=None))]
+    fn wait_for(
+        &self,
+        py: Python<'_>,
+        predicate: &Bound<'_, PyAny>,
+        timeout: Option<f64>,
+    ) -> PyResult<bool> {
+        let deadline = timeout
+            .filter(|value| {
+                value.is_finite() && *value >= 0.0 && *value < Duration::MAX.as_secs_f64()
+            })
+            .and_then(|value| Instant::now().checked_add(Duration::from_secs_f64(value)));
+
+        loop {
+            if predicate.call0()?.is_truthy()? {
+                return Ok(true);
+            }
+            // A negative timeout has already elapsed. Any other `None` deadline means no
+            // timeout was given, or it was infinite or too large to represent as a deadline;
+            // `wait` itself applies the right clamping semantics to those.
+            let per_wait_timeout = match deadline {
+                None if timeout.is_some_and(|value| value.is_sign_negative()) => {
+                    return Ok(false);
+                }
+                None => timeout,
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return predicate.call0()?.is_truthy();
+                    }
+                    Some((deadline - now).as_secs_f64())
+                }
+            };
+            self.wait(py, per_wait_timeout)?;
+        }
+    }
+
+    /// Wake up to `n` threads blocked in `wait`/`wait_for`. Must be called while holding
+    /// the condition; the woken thread(s) won't resume until this thread releases it.
+    #[pyo3(signature = (n=1))]
+    fn notify(&self, n: usize) -> PyResult<()> {
+        if !self.is_held() {
+            return Err(PyRuntimeError::new_err(
+                "cannot notify an unheld Condition",
+            ));
+        }
+        for _ in 0..n {
+            self.condvar.notify_one();
+        }
+        Ok(())
+    }
+
+    /// Wake every thread blocked in `wait`/`wait_for`. Must be called while holding the
+    /// condition.
+    fn notify_all(&self) -> PyResult<()> {
+        if !self.is_held() {
+            return Err(PyRuntimeError::new_err(
+                "cannot notify_all an unheld Condition",
+            ));
+        }
+        self.condvar.notify_all();
+        Ok(())
+    }
+}
@@ -1,4 +1,10 @@
-use std::time::Duration;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::io;
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd, RawFd};
+use std::sync::{Arc, Condvar, Mutex, OnceLock};
+use std::thread;
+use std::time::{Duration, Instant};
 
 use crate::submodule;
 use flume::{Receiver, RecvTimeoutError, Sender, TryRecvError, TrySendError};
@@ -6,24 +12,626 @@ use flume::{SendError, SendTimeoutError};
 use pyo3::exceptions::{PyRuntimeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::types::PyAny;
+use pyo3_async_runtimes::tokio::future_into_py;
+use tokio::runtime::{Builder as TokioRuntimeBuilder, Runtime as TokioRuntime};
+use tokio::sync::Notify;
 
 pyo3::create_exception!(queue_module, Empty, pyo3::exceptions::PyException);
 pyo3::create_exception!(queue_module, Full, pyo3::exceptions::PyException);
 
+/// Readable-only OS primitive used by `Queue::fileno` so external event loops (`selectors`,
+/// `asyncio.loop.add_reader`, a native epoll loop) can watch a `Queue` alongside their other
+/// file descriptors instead of polling it. Its readable state level-triggers on queue
+/// non-emptiness: `notify` is called once per successfully enqueued item and `consume` once
+/// per successfully dequeued item, so the kernel-tracked count always matches `qsize()`.
+#[cfg(target_os = "linux")]
+struct NotifyFd {
+    fd: OwnedFd,
+}
+
+#[cfg(target_os = "linux")]
+impl NotifyFd {
+    fn create(initial_count: usize) -> io::Result<Self> {
+        let raw = unsafe { libc::eventfd(0, libc::EFD_NONBLOCK | libc::EFD_SEMAPHORE) };
+        if raw < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let fd = unsafe { OwnedFd::from_raw_fd(raw) };
+        let notify_fd = Self { fd };
+        if initial_count > 0 {
+            notify_fd.add(initial_count as u64)?;
+        }
+        Ok(notify_fd)
+    }
+
+    fn add(&self, count: u64) -> io::Result<()> {
+        let written = unsafe {
+            libc::write(
+                self.fd.as_raw_fd(),
+                &count as *const u64 as *const libc::c_void,
+                std::mem::size_of::<u64>(),
+            )
+        };
+        if written < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    }
+
+    fn notify(&self) {
+        let _ = self.add(1);
+    }
+
+    fn consume(&self) {
+        let mut count: u64 = 0;
+        unsafe {
+            libc::read(
+                self.fd.as_raw_fd(),
+                &mut count as *mut u64 as *mut libc::c_void,
+                std::mem::size_of::<u64>(),
+            );
+        }
+    }
+
+    fn raw_fd(&self) -> RawFd {
+        self.fd.as_raw_fd()
+    }
+}
+
+/// Self-pipe fallback for non-Linux Unix targets: one byte is written per enqueue and drained
+/// per dequeue, giving the same level-triggered-on-non-emptiness semantics as the Linux
+/// `eventfd` backend without relying on a Linux-only syscall.
+#[cfg(all(unix, not(target_os = "linux")))]
+struct NotifyFd {
+    read_fd: OwnedFd,
+    write_fd: OwnedFd,
+}
+
+#[cfg(all(unix, not(target_os = "linux")))]
+impl NotifyFd {
+    fn create(initial_count: usize) -> io::Result<Self> {
+        let mut fds = [0; 2];
+        if unsafe { libc::pipe(fds.as_mut_ptr()) } < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let read_fd = unsafe { OwnedFd::from_raw_fd(fds[0]) };
+        let write_fd = unsafe { OwnedFd::from_raw_fd(fds[1]) };
+        for fd in [read_fd.as_raw_fd(), write_fd.as_raw_fd()] {
+            let flags = unsafe { libc::fcntl(fd, libc::F_GETFL) };
+            unsafe {
+                libc::fcntl(fd, libc::F_SETFL, flags | libc::O_NONBLOCK);
+            }
+        }
+        let notify_fd = Self { read_fd, write_fd };
+        if initial_count > 0 {
+            notify_fd.add(initial_count)?;
+        }
+        Ok(notify_fd)
+    }
+
+    fn add(&self, count: usize) -> io::Result<()> {
+        let buffer = vec![0u8; count];
+        let written = unsafe {
+            libc::write(
+                self.write_fd.as_raw_fd(),
+                buffer.as_ptr() as *const libc::c_void,
+                buffer.len(),
+            )
+        };
+        if written < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    }
+
+    fn notify(&self) {
+        let _ = self.add(1);
+    }
+
+    fn consume(&self) {
+        let mut byte = [0u8; 1];
+        unsafe {
+            libc::read(
+                self.read_fd.as_raw_fd(),
+                byte.as_mut_ptr() as *mut libc::c_void,
+                1,
+            );
+        }
+    }
+
+    fn raw_fd(&self) -> RawFd {
+        self.read_fd.as_raw_fd()
+    }
+}
+
+/// Tracks outstanding `put`s for `Queue::task_done`/`join`, mirroring the standard library's
+/// `queue.Queue` work-tracking API. Kept behind an `Arc` (like `NotifyFd`) so an `async_put`
+/// task, which outlives the borrow of `self` that created it, can still mark its put done.
+struct TaskTracker {
+    unfinished: Mutex<usize>,
+    all_done: Condvar,
+}
+
+impl TaskTracker {
+    fn new() -> Self {
+        Self {
+            unfinished: Mutex::new(0),
+            all_done: Condvar::new(),
+        }
+    }
+
+    fn mark_put(&self) {
+        *self.unfinished.lock().unwrap() += 1;
+    }
+
+    fn task_done(&self) -> PyResult<()> {
+        let mut unfinished = self.unfinished.lock().unwrap();
+        if *unfinished == 0 {
+            return Err(PyValueError::new_err("task_done() called too many times"));
+        }
+        *unfinished -= 1;
+        if *unfinished == 0 {
+            self.all_done.notify_all();
+        }
+        Ok(())
+    }
+
+    fn join(&self, py: Python<'_>) {
+        py.detach(|| {
+            let guard = self.unfinished.lock().unwrap();
+            let _guard = self
+                .all_done
+                .wait_while(guard, |unfinished| *unfinished != 0)
+                .unwrap();
+        });
+    }
+}
+
+/// Drives every `async_get`/`async_put` coroutine on a single, explicitly-owned OS thread
+/// instead of `pyo3_async_runtimes`'s own implicit, lazily-created multi-thread runtime.
+///
+/// That implicit runtime has no shutdown hook: its worker threads run for the lifetime of the
+/// process, and each one re-acquires the GIL (via `Python::attach`) to hand a coroutine's
+/// result back to the event loop. If the interpreter starts finalizing (`Py_Finalize`) while a
+/// worker thread is in the middle of acquiring or releasing the GIL — which nothing prevents on
+/// ordinary process exit, since those threads don't know Python is about to go away — the
+/// process aborts (`SIGABRT`) instead of just finishing the in-flight coroutine. Reducing this
+/// to a *single* thread we fully own lets us close the race deterministically: a Python
+/// `atexit` hook signals the thread to stop and `join()`s it, which only returns once the
+/// thread has truly exited, before `Py_Finalize` runs. By then there is no longer any thread
+/// left that could touch Python, so interpreter finalization is safe.
+struct AsyncDriver {
+    stop: Arc<Notify>,
+    driver_thread: Mutex<Option<thread::JoinHandle<()>>>,
+}
+
+impl AsyncDriver {
+    fn start() -> Self {
+        let runtime: &'static TokioRuntime = Box::leak(Box::new(
+            TokioRuntimeBuilder::new_current_thread()
+                .enable_time()
+                .build()
+                .expect("failed to build syncx async runtime"),
+        ));
+        pyo3_async_runtimes::tokio::init_with_runtime(runtime)
+            .expect("syncx async runtime already initialized");
+
+        let stop = Arc::new(Notify::new());
+        let driver_stop = Arc::clone(&stop);
+        let driver_thread = thread::Builder::new()
+            .name("syncx-async-driver".into())
+            .spawn(move || runtime.block_on(driver_stop.notified()))
+            .expect("failed to spawn syncx async driver thread");
+
+        Self {
+            stop,
+            driver_thread: Mutex::new(Some(driver_thread)),
+        }
+    }
+
+    /// Signals the driver thread to stop and blocks until it has actually exited. Called from
+    /// the `atexit` hook registered in `register()`, so it always runs on the main thread while
+    /// the interpreter is still fully alive.
+    fn shutdown(&self, py: Python<'_>) {
+        self.stop.notify_one();
+        let handle = self.driver_thread.lock().unwrap().take();
+        if let Some(handle) = handle {
+            py.detach(|| {
+                let _ = handle.join();
+            });
+        }
+    }
+}
+
+static ASYNC_DRIVER: OnceLock<AsyncDriver> = OnceLock::new();
+
+#[pyfunction]
+fn _shutdown_async_driver(py: Python<'_>) {
+    if let Some(driver) = ASYNC_DRIVER.get() {
+        driver.shutdown(py);
+    }
+}
+
 pub fn register(py: Python<'_>, parent: &Bound<'_, PyModule>) -> PyResult<()> {
+    ASYNC_DRIVER.get_or_init(AsyncDriver::start);
+
     let module = PyModule::new(py, "queue")?;
     module.add_class::<Queue>()?;
+    module.add_class::<PriorityQueue>()?;
+    module.add_class::<LifoQueue>()?;
     module.add("Empty", py.get_type::<Empty>())?;
     module.add("Full", py.get_type::<Full>())?;
+    py.import("atexit")?.call_method1(
+        "register",
+        (wrap_pyfunction!(_shutdown_async_driver, module.clone())?,),
+    )?;
     submodule::register_submodule(py, parent, &module, "syncx.queue")?;
     Ok(())
 }
 
+/// Blocks on `condvar` until `predicate(&state)` holds, then runs `apply` on the locked
+/// state and returns its result. Honors the same `block`/`timeout` semantics as
+/// `Queue::get`/`Queue::put`; returns `None` without calling `apply` if the deadline (or a
+/// non-blocking call) elapses before the predicate is satisfied.
+///
+/// A `MutexGuard` isn't `Ungil` (it can't be moved to another thread), so it can't be
+/// captured by the closure passed to `Python::detach` even transiently — the lock is
+/// therefore re-acquired from scratch inside the `detach`'d closure rather than carried in
+/// from the non-blocking check above.
+fn wait_then<T: Send, R: Send>(
+    py: Python<'_>,
+    mutex: &Mutex<T>,
+    condvar: &Condvar,
+    block: bool,
+    timeout: Option<f64>,
+    predicate: impl Fn(&T) -> bool + Send,
+    apply: impl FnOnce(&mut T) -> R + Send,
+) -> PyResult<Option<R>> {
+    {
+        let mut guard = mutex.lock().unwrap();
+        if predicate(&guard) {
+            return Ok(Some(apply(&mut guard)));
+        }
+    }
+    if !block {
+        return Ok(None);
+    }
+
+    let deadline = match timeout {
+        None => None,
+        Some(value) => Some(Instant::now() + timeout_to_duration(value)?),
+    };
+
+    Ok(py.detach(move || {
+        let mut guard = mutex.lock().unwrap();
+        loop {
+            if predicate(&guard) {
+                return Some(apply(&mut guard));
+            }
+            guard = match deadline {
+                None => condvar.wait(guard).unwrap(),
+                Some(deadline) => {
+                    let remaining = deadline.saturating_duration_since(Instant::now());
+                    let (guard, result) = condvar.wait_timeout(guard, remaining).unwrap();
+                    if result.timed_out() && !predicate(&guard) {
+                        return None;
+                    }
+                    guard
+                }
+            };
+        }
+    }))
+}
+
+/// A single slot in a `PriorityQueue`'s heap, ordered by calling the wrapped object's
+/// `__lt__` under the GIL, with a monotonic insertion counter as a tiebreaker so equal
+/// priority items come out in FIFO order instead of requiring a total order.
+struct HeapItem {
+    item: Py<PyAny>,
+    seq: u64,
+}
+
+impl HeapItem {
+    fn less_than(&self, other: &Self) -> bool {
+        Python::attach(|py| {
+            let lhs = self.item.bind(py);
+            let rhs = other.item.bind(py);
+            match lhs.lt(rhs) {
+                Ok(result) => result,
+                Err(_) => self.seq < other.seq,
+            }
+        })
+    }
+}
+
+impl PartialEq for HeapItem {
+    fn eq(&self, other: &Self) -> bool {
+        self.seq == other.seq
+    }
+}
+
+impl Eq for HeapItem {}
+
+impl PartialOrd for HeapItem {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for HeapItem {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // `BinaryHeap` is a max-heap; invert the comparison so the smallest item (by
+        // Python's `__lt__`) is the one `pop()` returns, matching stdlib `PriorityQueue`.
+        if self.less_than(other) {
+            Ordering::Greater
+        } else if other.less_than(self) {
+            Ordering::Less
+        } else {
+            other.seq.cmp(&self.seq)
+        }
+    }
+}
+
+struct HeapState {
+    heap: BinaryHeap<HeapItem>,
+    next_seq: u64,
+}
+
+/// A priority-ordered counterpart to `Queue`, matching stdlib `queue.PriorityQueue`: `get()`
+/// returns the smallest item first, as judged by the items' own `__lt__`.
+#[pyclass(module = "syncx.queue")]
+pub struct PriorityQueue {
+    state: Mutex<HeapState>,
+    not_empty: Condvar,
+    not_full: Condvar,
+    maxsize: Option<usize>,
+}
+
+#[pymethods]
+impl PriorityQueue {
+    #[new]
+    #[pyo3(signature = (maxsize=0))]
+    fn new(maxsize: usize) -> Self {
+        Self {
+            state: Mutex::new(HeapState {
+                heap: BinaryHeap::new(),
+                next_seq: 0,
+            }),
+            not_empty: Condvar::new(),
+            not_full: Condvar::new(),
+            maxsize: if maxsize == 0 { None } else { Some(maxsize) },
+        }
+    }
+
+    #[getter]
+    fn maxsize(&self) -> usize {
+        self.maxsize.unwrap_or(0)
+    }
+
+    fn qsize(&self) -> usize {
+        self.state.lock().unwrap().heap.len()
+    }
+
+    fn __len__(&self) -> usize {
+        self.qsize()
+    }
+
+    fn empty(&self) -> bool {
+        self.qsize() == 0
+    }
+
+    fn full(&self) -> bool {
+        match self.maxsize {
+            Some(limit) => self.qsize() >= limit,
+            None => false,
+        }
+    }
+
+    #[pyo3(signature = (item, block=true, timeout=None))]
+    fn put(
+        &self,
+        py: Python<'_>,
+        item: &Bound<'_, PyAny>,
+        block: bool,
+        timeout: Option<f64>,
+    ) -> PyResult<()> {
+        let maxsize = self.maxsize;
+        let object = item.clone().unbind();
+        let pushed = wait_then(
+            py,
+            &self.state,
+            &self.not_full,
+            block,
+            timeout,
+            |state| maxsize.is_none_or(|limit| state.heap.len() < limit),
+            |state| {
+                let seq = state.next_seq;
+                state.next_seq += 1;
+                state.heap.push(HeapItem { item: object, seq });
+            },
+        )?;
+        if pushed.is_none() {
+            return Err(Full::new_err("queue is full"));
+        }
+        self.not_empty.notify_one();
+        Ok(())
+    }
+
+    fn put_nowait(&self, py: Python<'_>, item: &Bound<'_, PyAny>) -> PyResult<()> {
+        self.put(py, item, false, None)
+    }
+
+    #[pyo3(signature = (block=true, timeout=None))]
+    fn get(&self, py: Python<'_>, block: bool, timeout: Option<f64>) -> PyResult<Py<PyAny>> {
+        let item = wait_then(
+            py,
+            &self.state,
+            &self.not_empty,
+            block,
+            timeout,
+            |state| !state.heap.is_empty(),
+            |state| state.heap.pop().expect("checked non-empty above").item,
+        )?;
+        let Some(item) = item else {
+            return Err(Empty::new_err("queue is empty"));
+        };
+        self.not_full.notify_one();
+        Ok(item)
+    }
+
+    fn get_nowait(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        self.get(py, false, None)
+    }
+}
+
+struct StackState {
+    items: Vec<Py<PyAny>>,
+}
+
+/// A LIFO (stack-ordered) counterpart to `Queue`, matching stdlib `queue.LifoQueue`.
+/// Backed by a `Mutex<Vec<_>>` rather than a `flume` channel since flume can't reorder.
+#[pyclass(module = "syncx.queue")]
+pub struct LifoQueue {
+    state: Mutex<StackState>,
+    not_empty: Condvar,
+    not_full: Condvar,
+    maxsize: Option<usize>,
+}
+
+#[pymethods]
+impl LifoQueue {
+    #[new]
+    #[pyo3(signature = (maxsize=0))]
+    fn new(maxsize: usize) -> Self {
+        Self {
+            state: Mutex::new(StackState { items: Vec::new() }),
+            not_empty: Condvar::new(),
+            not_full: Condvar::new(),
+            maxsize: if maxsize == 0 { None } else { Some(maxsize) },
+        }
+    }
+
+    #[getter]
+    fn maxsize(&self) -> usize {
+        self.maxsize.unwrap_or(0)
+    }
+
+    fn qsize(&self) -> usize {
+        self.state.lock().unwrap().items.len()
+    }
+
+    fn __len__(&self) -> usize {
+        self.qsize()
+    }
+
+    fn empty(&self) -> bool {
+        self.qsize() == 0
+    }
+
+    fn full(&self) -> bool {
+        match self.maxsize {
+            Some(limit) => self.qsize() >= limit,
+            None => false,
+        }
+    }
+
+    #[pyo3(signature = (item, block=true, timeout=None))]
+    fn put(
+        &self,
+        py: Python<'_>,
+        item: &Bound<'_, PyAny>,
+        block: bool,
+        timeout: Option<f64>,
+    ) -> PyResult<()> {
+        let maxsize = self.maxsize;
+        let object = item.clone().unbind();
+        let pushed = wait_then(
+            py,
+            &self.state,
+            &self.not_full,
+            block,
+            timeout,
+            |state| maxsize.is_none_or(|limit| state.items.len() < limit),
+            |state| state.items.push(object),
+        )?;
+        if pushed.is_none() {
+            return Err(Full::new_err("queue is full"));
+        }
+        self.not_empty.notify_one();
+        Ok(())
+    }
+
+    fn put_nowait(&self, py: Python<'_>, item: &Bound<'_, PyAny>) -> PyResult<()> {
+        self.put(py, item, false, None)
+    }
+
+    #[pyo3(signature = (block=true, timeout=None))]
+    fn get(&self, py: Python<'_>, block: bool, timeout: Option<f64>) -> PyResult<Py<PyAny>> {
+        let item = wait_then(
+            py,
+            &self.state,
+            &self.not_empty,
+            block,
+            timeout,
+            |state| !state.items.is_empty(),
+            |state| state.items.pop().expect("checked non-empty above"),
+        )?;
+        let Some(item) = item else {
+            return Err(Empty::new_err("queue is empty"));
+        };
+        self.not_full.notify_one();
+        Ok(item)
+    }
+
+    fn get_nowait(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        self.get(py, false, None)
+    }
+}
+
 #[pyclass(module = "syncx.queue")]
 pub struct Queue {
     sender: Sender<Py<PyAny>>,
     receiver: Receiver<Py<PyAny>>,
     maxsize: Option<usize>,
+    notify_fd: Mutex<Option<Arc<NotifyFd>>>,
+    tasks: Arc<TaskTracker>,
+}
+
+impl Queue {
+    fn notify_enqueued(&self) {
+        if let Some(notify_fd) = self.notify_fd.lock().unwrap().as_ref() {
+            notify_fd.notify();
+        }
+    }
+
+    fn notify_dequeued(&self) {
+        if let Some(notify_fd) = self.notify_fd.lock().unwrap().as_ref() {
+            notify_fd.consume();
+        }
+    }
+
+    /// Clone of the notification fd, if `fileno()` has already created one, to hand into a
+    /// `'static` async task that outlives this borrow of `self`.
+    fn notify_fd_handle(&self) -> Option<Arc<NotifyFd>> {
+        self.notify_fd.lock().unwrap().clone()
+    }
+
+    /// Returns the notification fd, creating it — seeded with the current `qsize` so it
+    /// starts in sync with the queue — the first time any caller needs one. The read of
+    /// `qsize` and the publish of the new `Arc` happen under the same lock that
+    /// `notify_enqueued`/`notify_dequeued` check, so two callers racing to create it can
+    /// never end up with two fds (or have one observe a half-published slot) for one queue.
+    fn notify_fd_or_create(&self) -> PyResult<Arc<NotifyFd>> {
+        let mut slot = self.notify_fd.lock().unwrap();
+        if let Some(existing) = slot.as_ref() {
+            return Ok(Arc::clone(existing));
+        }
+        let created = Arc::new(NotifyFd::create(self.qsize()).map_err(|err| {
+            PyRuntimeError::new_err(format!("failed to create queue notification fd: {err}"))
+        })?);
+        *slot = Some(Arc::clone(&created));
+        Ok(created)
+    }
 }
 
 #[pymethods]
@@ -41,6 +649,8 @@ impl Queue {
             sender,
             receiver,
             maxsize: if maxsize == 0 { None } else { Some(maxsize) },
+            notify_fd: Mutex::new(None),
+            tasks: Arc::new(TaskTracker::new()),
         }
     }
 
@@ -80,7 +690,11 @@ impl Queue {
 
         if !block {
             return match self.sender.try_send(object) {
-                Ok(()) => Ok(()),
+                Ok(()) => {
+                    self.notify_enqueued();
+                    self.tasks.mark_put();
+                    Ok(())
+                }
                 Err(TrySendError::Full(_)) => Err(Full::new_err("queue is full")),
                 Err(TrySendError::Disconnected(_)) => {
                     Err(PyRuntimeError::new_err("queue disconnected"))
@@ -95,14 +709,22 @@ impl Queue {
 
         match duration {
             Some(duration) => match py.detach(|| self.sender.send_timeout(object, duration)) {
-                Ok(()) => Ok(()),
+                Ok(()) => {
+                    self.notify_enqueued();
+                    self.tasks.mark_put();
+                    Ok(())
+                }
                 Err(SendTimeoutError::Timeout(_)) => Err(Full::new_err("queue is full")),
                 Err(SendTimeoutError::Disconnected(_)) => {
                     Err(PyRuntimeError::new_err("queue disconnected"))
                 }
             },
             None => match py.detach(|| self.sender.send(object)) {
-                Ok(()) => Ok(()),
+                Ok(()) => {
+                    self.notify_enqueued();
+                    self.tasks.mark_put();
+                    Ok(())
+                }
                 Err(SendError(_)) => Err(PyRuntimeError::new_err("queue disconnected")),
             },
         }
@@ -116,7 +738,10 @@ impl Queue {
     fn get(&self, py: Python<'_>, block: bool, timeout: Option<f64>) -> PyResult<Py<PyAny>> {
         if !block {
             return match self.receiver.try_recv() {
-                Ok(value) => Ok(value),
+                Ok(value) => {
+                    self.notify_dequeued();
+                    Ok(value)
+                }
                 Err(TryRecvError::Empty) => Err(Empty::new_err("queue is empty")),
                 Err(TryRecvError::Disconnected) => {
                     Err(PyRuntimeError::new_err("queue disconnected"))
@@ -131,14 +756,20 @@ impl Queue {
 
         match duration {
             Some(duration) => match py.detach(|| self.receiver.recv_timeout(duration)) {
-                Ok(value) => Ok(value),
+                Ok(value) => {
+                    self.notify_dequeued();
+                    Ok(value)
+                }
                 Err(RecvTimeoutError::Timeout) => Err(Empty::new_err("queue is empty")),
                 Err(RecvTimeoutError::Disconnected) => {
                     Err(PyRuntimeError::new_err("queue disconnected"))
                 }
             },
             None => match py.detach(|| self.receiver.recv()) {
-                Ok(value) => Ok(value),
+                Ok(value) => {
+                    self.notify_dequeued();
+                    Ok(value)
+                }
                 Err(flume::RecvError::Disconnected) => {
                     Err(PyRuntimeError::new_err("queue disconnected"))
                 }
@@ -149,6 +780,74 @@ impl Queue {
     fn get_nowait(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
         self.get(py, false, None)
     }
+
+    /// Coroutine equivalent of blocking `get()`: awaits until an item is available without
+    /// blocking the asyncio event loop thread, so a queue can be shared between thread-based
+    /// producers using `put` and asyncio consumers using `await q.async_get()`.
+    fn async_get<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let receiver = self.receiver.clone();
+        let notify_fd = self.notify_fd_handle();
+        future_into_py(py, async move {
+            match receiver.recv_async().await {
+                Ok(value) => {
+                    if let Some(notify_fd) = &notify_fd {
+                        notify_fd.consume();
+                    }
+                    Ok(value)
+                }
+                Err(flume::RecvError::Disconnected) => {
+                    Err(PyRuntimeError::new_err("queue disconnected"))
+                }
+            }
+        })
+    }
+
+    /// Coroutine equivalent of blocking `put()`: awaits until there is room in a bounded queue
+    /// without blocking the asyncio event loop thread.
+    fn async_put<'py>(
+        &self,
+        py: Python<'py>,
+        item: &Bound<'py, PyAny>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let object = item.clone().unbind();
+        let sender = self.sender.clone();
+        let notify_fd = self.notify_fd_handle();
+        let tasks = Arc::clone(&self.tasks);
+        future_into_py(py, async move {
+            match sender.send_async(object).await {
+                Ok(()) => {
+                    if let Some(notify_fd) = &notify_fd {
+                        notify_fd.notify();
+                    }
+                    tasks.mark_put();
+                    Ok(())
+                }
+                Err(flume::SendError(_)) => Err(PyRuntimeError::new_err("queue disconnected")),
+            }
+        })
+    }
+
+    /// Returns a readable file descriptor that is level-triggered on queue non-emptiness, so
+    /// the queue can be registered with `selectors`, `asyncio.loop.add_reader`, or a native
+    /// epoll loop. Created lazily on first call (most queues never need one) and cached for
+    /// the lifetime of the queue; the fd is seeded with the current `qsize` so it starts in
+    /// sync with whatever is already enqueued. See `notify_fd_or_create` for how the creation
+    /// race against concurrent `put`/`get` calls is avoided. Callers still use
+    /// `get_nowait`/`get` to actually retrieve items.
+    fn fileno(&self) -> PyResult<RawFd> {
+        Ok(self.notify_fd_or_create()?.raw_fd())
+    }
+
+    /// Mark a previously enqueued task as complete. Raises `ValueError` if called more
+    /// times than there were items put onto the queue.
+    fn task_done(&self) -> PyResult<()> {
+        self.tasks.task_done()
+    }
+
+    /// Block until every item put onto the queue has been acknowledged via `task_done()`.
+    fn join(&self, py: Python<'_>) {
+        self.tasks.join(py);
+    }
 }
 
 fn timeout_to_duration(timeout: f64) -> PyResult<Duration> {
@@ -82,6 +82,50 @@ impl ConcurrentSet {
     fn ensure_hashable(value: &Bound<'_, PyAny>) -> PyResult<PyKey> {
         PyKey::new(value)
     }
+
+    /// Collects the elements of `other` into a fresh `DashSet` so membership can be
+    /// probed in O(1) via `contains`, accepting either another `ConcurrentSet` (read via
+    /// a point-in-time snapshot) or any Python iterable. Reuses `PyKey`/`ensure_hashable`
+    /// so each element's Python hash is computed exactly once.
+    fn collect_set(other: &Bound<'_, PyAny>) -> PyResult<DashSet<PyKey>> {
+        if let Ok(set) = other.extract::<PyRef<'_, ConcurrentSet>>() {
+            let collected = DashSet::new();
+            for entry in set.inner.iter() {
+                collected.insert(entry.clone());
+            }
+            return Ok(collected);
+        }
+        let collected = DashSet::new();
+        for item in other.try_iter()? {
+            collected.insert(Self::ensure_hashable(&item?)?);
+        }
+        Ok(collected)
+    }
+
+    fn from_set(keys: DashSet<PyKey>) -> Self {
+        Self {
+            inner: Arc::new(keys),
+        }
+    }
+}
+
+/// Iterates a snapshot of the set's elements taken up front by `ConcurrentSet::__iter__`.
+/// Backed by an owned `Vec` rather than a live `DashSet` iterator, so concurrent mutation
+/// during iteration can't deadlock a held shard and always sees a consistent view.
+#[pyclass(module = "syncx.set")]
+struct SetSnapshotIter {
+    items: std::vec::IntoIter<Py<PyAny>>,
+}
+
+#[pymethods]
+impl SetSnapshotIter {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<'_, Self>) -> Option<Py<PyAny>> {
+        slf.items.next()
+    }
 }
 
 #[pymethods]
@@ -112,10 +156,16 @@ impl ConcurrentSet {
         Ok(())
     }
 
-    fn discard(&self, value: &Bound<'_, PyAny>) -> PyResult<()> {
+    /// Inserts `value` only if it isn't already present, returning whether it was newly added.
+    fn add_if_absent(&self, value: &Bound<'_, PyAny>) -> PyResult<bool> {
         let key = Self::ensure_hashable(value)?;
-        self.inner.remove(&key);
-        Ok(())
+        Ok(self.inner.insert(key))
+    }
+
+    /// Removes `value` if present, returning whether it was actually removed.
+    fn discard(&self, value: &Bound<'_, PyAny>) -> PyResult<bool> {
+        let key = Self::ensure_hashable(value)?;
+        Ok(self.inner.remove(&key).is_some())
     }
 
     fn remove(&self, value: &Bound<'_, PyAny>) -> PyResult<()> {
@@ -158,4 +208,205 @@ impl ConcurrentSet {
         }
         Ok(())
     }
+
+    /// Returns a new `ConcurrentSet` holding every element in `self` or `other`, each read
+    /// from a snapshot taken at call time.
+    fn union(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let merged = Self::collect_set(other)?;
+        for entry in self.inner.iter() {
+            merged.insert(entry.clone());
+        }
+        Ok(Self::from_set(merged))
+    }
+
+    #[pyo3(name = "__or__")]
+    fn union_alias(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        self.union(other)
+    }
+
+    /// Returns a new `ConcurrentSet` holding the elements present in both `self` and `other`.
+    fn intersection(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let other_set = Self::collect_set(other)?;
+        let result = DashSet::new();
+        for entry in self.inner.iter() {
+            if other_set.contains(entry.key()) {
+                result.insert(entry.clone());
+            }
+        }
+        Ok(Self::from_set(result))
+    }
+
+    #[pyo3(name = "__and__")]
+    fn intersection_alias(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        self.intersection(other)
+    }
+
+    /// Returns a new `ConcurrentSet` holding the elements of `self` that are not in `other`.
+    fn difference(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let other_set = Self::collect_set(other)?;
+        let result = DashSet::new();
+        for entry in self.inner.iter() {
+            if !other_set.contains(entry.key()) {
+                result.insert(entry.clone());
+            }
+        }
+        Ok(Self::from_set(result))
+    }
+
+    #[pyo3(name = "__sub__")]
+    fn difference_alias(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        self.difference(other)
+    }
+
+    /// Returns a new `ConcurrentSet` holding the elements in exactly one of `self`, `other`.
+    fn symmetric_difference(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let other_set = Self::collect_set(other)?;
+        let result = DashSet::new();
+        for entry in self.inner.iter() {
+            if !other_set.contains(entry.key()) {
+                result.insert(entry.clone());
+            }
+        }
+        for entry in other_set.iter() {
+            if !self.inner.contains(entry.key()) {
+                result.insert(entry.clone());
+            }
+        }
+        Ok(Self::from_set(result))
+    }
+
+    #[pyo3(name = "__xor__")]
+    fn symmetric_difference_alias(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        self.symmetric_difference(other)
+    }
+
+    /// Inserts every element of `other` into `self` in place.
+    fn update(&self, other: &Bound<'_, PyAny>) -> PyResult<()> {
+        let other_set = Self::collect_set(other)?;
+        for entry in other_set.iter() {
+            self.inner.insert(entry.clone());
+        }
+        Ok(())
+    }
+
+    #[pyo3(name = "__ior__")]
+    fn update_alias(&self, other: &Bound<'_, PyAny>) -> PyResult<()> {
+        self.update(other)
+    }
+
+    /// Removes, in place, every element of `self` that is not also in `other`.
+    fn intersection_update(&self, other: &Bound<'_, PyAny>) -> PyResult<()> {
+        let other_set = Self::collect_set(other)?;
+        let stale: Vec<PyKey> = self
+            .inner
+            .iter()
+            .filter(|entry| !other_set.contains(entry.key()))
+            .map(|entry| entry.clone())
+            .collect();
+        for key in stale {
+            self.inner.remove(&key);
+        }
+        Ok(())
+    }
+
+    /// Removes, in place, every element of `self` that is also in `other`.
+    fn difference_update(&self, other: &Bound<'_, PyAny>) -> PyResult<()> {
+        let other_set = Self::collect_set(other)?;
+        let stale: Vec<PyKey> = self
+            .inner
+            .iter()
+            .filter(|entry| other_set.contains(entry.key()))
+            .map(|entry| entry.clone())
+            .collect();
+        for key in stale {
+            self.inner.remove(&key);
+        }
+        Ok(())
+    }
+
+    /// Replaces `self`, in place, with the symmetric difference of `self` and `other`.
+    fn symmetric_difference_update(&self, other: &Bound<'_, PyAny>) -> PyResult<()> {
+        let other_set = Self::collect_set(other)?;
+        let shared: Vec<PyKey> = self
+            .inner
+            .iter()
+            .filter(|entry| other_set.contains(entry.key()))
+            .map(|entry| entry.clone())
+            .collect();
+        for key in &shared {
+            self.inner.remove(key);
+        }
+        for entry in other_set.iter() {
+            if !shared.contains(entry.key()) {
+                self.inner.insert(entry.clone());
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether every element of `self` is also in `other`.
+    fn issubset(&self, other: &Bound<'_, PyAny>) -> PyResult<bool> {
+        let other_set = Self::collect_set(other)?;
+        Ok(self.inner.iter().all(|entry| other_set.contains(entry.key())))
+    }
+
+    #[pyo3(name = "__le__")]
+    fn issubset_alias(&self, other: &Bound<'_, PyAny>) -> PyResult<bool> {
+        self.issubset(other)
+    }
+
+    /// Whether every element of `other` is also in `self`.
+    fn issuperset(&self, other: &Bound<'_, PyAny>) -> PyResult<bool> {
+        let other_set = Self::collect_set(other)?;
+        let result = other_set.iter().all(|entry| self.inner.contains(entry.key()));
+        Ok(result)
+    }
+
+    #[pyo3(name = "__ge__")]
+    fn issuperset_alias(&self, other: &Bound<'_, PyAny>) -> PyResult<bool> {
+        self.issuperset(other)
+    }
+
+    /// Whether `self` and `other` share no elements.
+    fn isdisjoint(&self, other: &Bound<'_, PyAny>) -> PyResult<bool> {
+        let other_set = Self::collect_set(other)?;
+        Ok(self.inner.iter().all(|entry| !other_set.contains(entry.key())))
+    }
+
+    /// Equality against another `ConcurrentSet` or any iterable with the same elements.
+    fn __eq__(&self, other: &Bound<'_, PyAny>) -> PyResult<bool> {
+        let Ok(other_set) = Self::collect_set(other) else {
+            return Ok(false);
+        };
+        if other_set.len() != self.inner.len() {
+            return Ok(false);
+        }
+        Ok(self.inner.iter().all(|entry| other_set.contains(entry.key())))
+    }
+
+    /// Removes and returns an arbitrary element. Raises `KeyError` if the set is empty.
+    ///
+    /// Retries against a fresh candidate if a racing caller already removed the one this
+    /// call saw, so two threads calling `pop()` concurrently never get handed the same
+    /// element (plain snapshot-then-remove would let that happen).
+    fn pop(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        loop {
+            let Some(key) = self.inner.iter().next().map(|entry| entry.clone()) else {
+                return Err(PyKeyError::new_err("pop from an empty set"));
+            };
+            if let Some(removed) = self.inner.remove(&key) {
+                return Ok(removed.clone_object(py));
+            }
+        }
+    }
+
+    /// Returns an iterator over a snapshot of the set's elements taken at call time.
+    fn __iter__(&self, py: Python<'_>) -> PyResult<Py<SetSnapshotIter>> {
+        let items: Vec<Py<PyAny>> = self
+            .inner
+            .iter()
+            .map(|entry| entry.clone_object(py))
+            .collect();
+        Py::new(py, SetSnapshotIter { items: items.into_iter() })
+    }
 }